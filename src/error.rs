@@ -0,0 +1,109 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::de;
+
+thread_local! {
+    static CURRENT_PATH: RefCell<Vec<PathSegment>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps a segment pushed onto `CURRENT_PATH` for as long as it's alive,
+/// popping it on drop so a panic unwinding through `Deserialize` can't leave
+/// a stale segment behind for the next request on this worker thread.
+pub(crate) struct PathGuard;
+
+impl PathGuard {
+    pub(crate) fn push_key(key: String) -> Self {
+        CURRENT_PATH.with(|path| path.borrow_mut().push(PathSegment::Key(key)));
+        PathGuard
+    }
+
+    pub(crate) fn push_index(index: usize) -> Self {
+        CURRENT_PATH.with(|path| path.borrow_mut().push(PathSegment::Index(index)));
+        PathGuard
+    }
+}
+
+impl Drop for PathGuard {
+    fn drop(&mut self) {
+        CURRENT_PATH.with(|path| {
+            path.borrow_mut().pop();
+        });
+    }
+}
+
+fn current_path() -> Vec<PathSegment> {
+    CURRENT_PATH.with(|path| path.borrow().clone())
+}
+
+/// A map key or sequence index, e.g. the `currency` and `[0]` in
+/// `currency[0]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Formats as `a.b[0]: <message>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathError {
+    path: Vec<PathSegment>,
+    message: String,
+}
+
+impl PathError {
+    pub fn new(message: impl Into<String>) -> Self {
+        PathError {
+            path: current_path(),
+            message: message.into(),
+        }
+    }
+
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut segments = self.path.iter();
+        if let Some(first) = segments.next() {
+            match first {
+                PathSegment::Key(k) => write!(f, "{k}")?,
+                PathSegment::Index(i) => write!(f, "[{i}]")?,
+            }
+            for segment in segments {
+                match segment {
+                    PathSegment::Key(k) => write!(f, ".{k}")?,
+                    PathSegment::Index(i) => write!(f, "[{i}]")?,
+                }
+            }
+            write!(f, ": {}", self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl de::Error for PathError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        PathError::new(msg.to_string())
+    }
+}
+
+impl IntoResponse for PathError {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response()
+    }
+}
@@ -1,13 +1,68 @@
-use crate::{N, Number};
+use crate::error::PathGuard;
+use crate::{N, Number, PathError};
 
 use super::Value;
+use base64::Engine;
 use log::debug;
 use serde::{
     Deserialize, Deserializer,
     de::{self, MapAccess, SeqAccess, Visitor},
 };
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// Percent-decodes `%XX` escapes (e.g. `us%64` -> `"usd"`), borrowing
+/// unchanged when there's nothing to decode. Only called on `XStr`.
+fn requote(s: &str) -> Cow<'_, str> {
+    if !s.as_bytes().contains(&b'%') {
+        return Cow::Borrowed(s);
+    }
+
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex_digit(bytes[i + 1]), hex_digit(bytes[i + 2])) {
+                out.push(hi * 16 + lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&out).into_owned())
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Upper bound on an upload file read into memory via `deserialize_bytes`/
+/// `deserialize_byte_buf`. This runs synchronously on the tokio worker
+/// thread deserializing the request, so it's checked before the read rather
+/// than left to grow unbounded.
+const MAX_INLINE_UPLOAD_BYTES: u64 = 10 * 1024 * 1024;
+
+fn read_upload_file_bytes(path: &str) -> Result<Vec<u8>, PathError> {
+    let metadata = std::fs::metadata(path).map_err(de::Error::custom)?;
+    if metadata.len() > MAX_INLINE_UPLOAD_BYTES {
+        return Err(de::Error::custom(format!(
+            "upload file is {} bytes, which exceeds the {}-byte limit for reading into memory",
+            metadata.len(),
+            MAX_INLINE_UPLOAD_BYTES
+        )));
+    }
+    std::fs::read(path).map_err(de::Error::custom)
+}
+
 struct ParamsValueVisitor;
 
 impl<'de> Visitor<'de> for ParamsValueVisitor {
@@ -33,6 +88,14 @@ impl<'de> Visitor<'de> for ParamsValueVisitor {
         Ok(Value::Number(Number::from(v)))
     }
 
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Value::Number(Number::from(v)))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Value::Number(Number::from(v)))
+    }
+
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: de::Error,
@@ -93,6 +156,7 @@ impl<'de> Deserialize<'de> for Value {
 
 struct MapAccessor {
     map: std::collections::hash_map::IntoIter<String, Value>,
+    current_key: Option<String>,
     current_value: Option<Value>,
 }
 
@@ -100,13 +164,14 @@ impl MapAccessor {
     fn new(map: HashMap<String, Value>) -> Self {
         MapAccessor {
             map: map.into_iter(),
+            current_key: None,
             current_value: None,
         }
     }
 }
 
 impl<'de> MapAccess<'de> for MapAccessor {
-    type Error = serde::de::value::Error;
+    type Error = PathError;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
     where
@@ -114,6 +179,7 @@ impl<'de> MapAccess<'de> for MapAccessor {
     {
         match self.map.next() {
             Some((key, value)) => {
+                self.current_key = Some(key.clone());
                 self.current_value = Some(value);
                 seed.deserialize(key.into_deserializer()).map(Some)
             }
@@ -125,33 +191,114 @@ impl<'de> MapAccess<'de> for MapAccessor {
     where
         V: de::DeserializeSeed<'de>,
     {
-        match self.current_value.take() {
-            Some(value) => seed.deserialize(value),
-            None => Err(de::Error::custom("value is missing")),
+        match (self.current_key.take(), self.current_value.take()) {
+            (Some(key), Some(value)) => {
+                let _guard = PathGuard::push_key(key);
+                seed.deserialize(value)
+            }
+            _ => Err(de::Error::custom("value is missing")),
         }
     }
 }
 
 struct SeqAccessor {
     seq: std::vec::IntoIter<Value>,
+    index: usize,
 }
 
 impl<'de> SeqAccess<'de> for SeqAccessor {
-    type Error = serde::de::value::Error;
+    type Error = PathError;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
     where
         T: de::DeserializeSeed<'de>,
     {
         match self.seq.next() {
-            Some(value) => seed.deserialize(value).map(Some),
+            Some(value) => {
+                let index = self.index;
+                self.index += 1;
+                let _guard = PathGuard::push_index(index);
+                seed.deserialize(value).map(Some)
+            }
             None => Ok(None),
         }
     }
 }
 
+/// Drives a single `(variant, payload)` pair through serde's enum protocol,
+/// for externally-tagged enums represented as a one-key `Value::Object`.
+struct EnumDeserializer {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = PathError;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+struct VariantDeserializer {
+    value: Value,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantDeserializer {
+    type Error = PathError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Err(de::Error::custom(
+            "expected unit variant, found a value for externally tagged enum",
+        ))
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.value)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(vec) => visitor.visit_seq(SeqAccessor {
+                seq: vec.into_iter(),
+                index: 0,
+            }),
+            _ => Err(de::Error::custom(
+                "expected tuple variant, found non-array payload",
+            )),
+        }
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Object(map) => visitor.visit_map(MapAccessor::new(map)),
+            _ => Err(de::Error::custom(
+                "expected struct variant, found non-object payload",
+            )),
+        }
+    }
+}
+
 impl<'de> Deserializer<'de> for Value {
-    type Error = serde::de::value::Error;
+    type Error = PathError;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
@@ -164,11 +311,14 @@ impl<'de> Deserializer<'de> for Value {
                 N::PosInt(i) => visitor.visit_u64(i),
                 N::NegInt(i) => visitor.visit_i64(i),
                 N::Float(f) => visitor.visit_f64(f),
+                N::I128(i) => visitor.visit_i128(i),
+                N::U128(i) => visitor.visit_u128(i),
             },
             Value::String(s) => visitor.visit_string(s),
             Value::Object(map) => visitor.visit_map(MapAccessor::new(map)),
             Value::Array(vec) => visitor.visit_seq(SeqAccessor {
                 seq: vec.into_iter(),
+                index: 0,
             }),
             Value::XStr(s) => visitor.visit_string(s),
             Value::UploadFile(file) => {
@@ -190,7 +340,7 @@ impl<'de> Deserializer<'de> for Value {
 
     fn deserialize_enum<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Self::Error>
@@ -207,6 +357,19 @@ impl<'de> Deserializer<'de> for Value {
                 // For string values from JSON, also handle the same way
                 visitor.visit_enum(s.into_deserializer())
             },
+            // A nested key like `kind[amount]=10` arrives as a single-entry object:
+            // the one key is the variant name and its value is the variant payload.
+            Value::Object(mut map) => {
+                if map.len() != 1 {
+                    return Err(de::Error::custom(format!(
+                        "expected externally tagged enum `{}` to have exactly one key, found {}",
+                        name,
+                        map.len()
+                    )));
+                }
+                let (variant, value) = map.drain().next().expect("checked len == 1 above");
+                visitor.visit_enum(EnumDeserializer { variant, value })
+            }
             // For other types, use the default implementation
             _ => self.deserialize_any(visitor),
         }
@@ -217,7 +380,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => match s.to_lowercase().as_str() {
+            Value::XStr(s) => match requote(&s).to_lowercase().as_str() {
                 "true" | "1" | "on" | "yes" => visitor.visit_bool(true),
                 "false" | "0" | "off" | "no" => visitor.visit_bool(false),
                 _ => Err(de::Error::custom("invalid boolean value")),
@@ -231,7 +394,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_i8(v)),
@@ -244,7 +407,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_i16(v)),
@@ -257,7 +420,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_i32(v)),
@@ -271,7 +434,7 @@ impl<'de> Deserializer<'de> for Value {
     {
         debug!("deserialize_i64 self: {:?}", self);
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_i64(v)),
@@ -279,12 +442,25 @@ impl<'de> Deserializer<'de> for Value {
         }
     }
 
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::XStr(s) => requote(&s)
+                .parse()
+                .map_err(de::Error::custom)
+                .and_then(|v| visitor.visit_i128(v)),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_u8(v)),
@@ -297,7 +473,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_u16(v)),
@@ -310,7 +486,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_u32(v)),
@@ -323,7 +499,7 @@ impl<'de> Deserializer<'de> for Value {
         V: Visitor<'de>,
     {
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_u64(v)),
@@ -331,13 +507,26 @@ impl<'de> Deserializer<'de> for Value {
         }
     }
 
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::XStr(s) => requote(&s)
+                .parse()
+                .map_err(de::Error::custom)
+                .and_then(|v| visitor.visit_u128(v)),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         debug!("deserialize_f32 self: {:?}", self);
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_f32(v)),
@@ -351,7 +540,7 @@ impl<'de> Deserializer<'de> for Value {
     {
         debug!("deserialize_f64 self: {:?}", self);
         match self {
-            Value::XStr(s) => s
+            Value::XStr(s) => requote(&s)
                 .parse()
                 .map_err(de::Error::custom)
                 .and_then(|v| visitor.visit_f64(v)),
@@ -365,7 +554,8 @@ impl<'de> Deserializer<'de> for Value {
     {
         match self {
             Value::XStr(s) => {
-                let mut chars = s.chars();
+                let decoded = requote(&s);
+                let mut chars = decoded.chars();
                 match (chars.next(), chars.next()) {
                     (Some(c), None) => visitor.visit_char(c),
                     _ => Err(de::Error::custom("invalid char value")),
@@ -375,6 +565,26 @@ impl<'de> Deserializer<'de> for Value {
         }
     }
 
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::XStr(s) => visitor.visit_string(requote(&s).into_owned()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::XStr(s) => visitor.visit_string(requote(&s).into_owned()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -385,8 +595,58 @@ impl<'de> Deserializer<'de> for Value {
         }
     }
 
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::UploadFile(file) => {
+                let bytes = read_upload_file_bytes(&file.temp_file_path)?;
+                visitor.visit_bytes(&bytes)
+            }
+            Value::XStr(s) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(requote(&s).as_ref())
+                    .map_err(de::Error::custom)?;
+                visitor.visit_bytes(&bytes)
+            }
+            Value::String(s) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(s)
+                    .map_err(de::Error::custom)?;
+                visitor.visit_bytes(&bytes)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::UploadFile(file) => {
+                let bytes = read_upload_file_bytes(&file.temp_file_path)?;
+                visitor.visit_byte_buf(bytes)
+            }
+            Value::XStr(s) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(requote(&s).as_ref())
+                    .map_err(de::Error::custom)?;
+                visitor.visit_byte_buf(bytes)
+            }
+            Value::String(s) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(s)
+                    .map_err(de::Error::custom)?;
+                visitor.visit_byte_buf(bytes)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     serde::forward_to_deserialize_any! {
-        str string bytes byte_buf unit newtype_struct seq tuple
+        unit newtype_struct seq tuple
         tuple_struct map unit_struct struct identifier ignored_any
     }
 }
@@ -403,7 +663,7 @@ mod tests {
     };
     use axum::extract::FromRequest;
     use serde::{Deserialize, Serialize};
-    use crate::Params;
+    use crate::{Params, UploadFile};
 
     // Define the enum and structs for testing
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -430,6 +690,246 @@ mod tests {
         pub currency: Currency,
     }
 
+    #[derive(Debug, Deserialize, PartialEq)]
+    pub enum Shape {
+        #[serde(rename = "circle")]
+        Circle { radius: f64 },
+        #[serde(rename = "point")]
+        Point(f64, f64),
+    }
+
+    #[test]
+    fn test_deserialize_enum_struct_variant_from_object() {
+        let mut fields = HashMap::new();
+        fields.insert("radius".to_string(), Value::XStr("2.5".to_string()));
+        let mut variant = HashMap::new();
+        variant.insert("circle".to_string(), Value::Object(fields));
+
+        let shape = Shape::deserialize(Value::Object(variant)).unwrap();
+        assert_eq!(shape, Shape::Circle { radius: 2.5 });
+    }
+
+    #[test]
+    fn test_deserialize_enum_tuple_variant_from_object() {
+        let coords = Value::Array(vec![
+            Value::XStr("1.0".to_string()),
+            Value::XStr("2.0".to_string()),
+        ]);
+        let mut variant = HashMap::new();
+        variant.insert("point".to_string(), coords);
+
+        let shape = Shape::deserialize(Value::Object(variant)).unwrap();
+        assert_eq!(shape, Shape::Point(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_deserialize_error_includes_field_path() {
+        let mut currency_fields = HashMap::new();
+        currency_fields.insert("amount".to_string(), Value::XStr("10".to_string()));
+        currency_fields.insert(
+            "currency_code".to_string(),
+            Value::XStr("not-a-code".to_string()),
+        );
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), Value::XStr("tx_1".to_string()));
+        fields.insert("currency".to_string(), Value::Object(currency_fields));
+
+        let err = Transaction::deserialize(Value::Object(fields)).unwrap_err();
+        assert!(
+            err.to_string().starts_with("currency.currency_code"),
+            "expected error to be prefixed with the field path, got: {err}"
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    pub struct Ledger {
+        pub balance: i128,
+        pub account_id: u128,
+    }
+
+    #[test]
+    fn test_deserialize_i128_and_u128_from_xstr() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "balance".to_string(),
+            Value::XStr("-170141183460469231731687303715884105728".to_string()),
+        );
+        fields.insert(
+            "account_id".to_string(),
+            Value::XStr("340282366920938463463374607431768211455".to_string()),
+        );
+
+        let ledger = Ledger::deserialize(Value::Object(fields)).unwrap();
+        assert_eq!(ledger.balance, i128::MIN);
+        assert_eq!(ledger.account_id, u128::MAX);
+    }
+
+    #[test]
+    fn test_number_from_i128_picks_narrowest_representation() {
+        assert_eq!(Number::from(10_i128).0, N::PosInt(10));
+        assert_eq!(Number::from(-10_i128).0, N::NegInt(-10));
+        assert_eq!(
+            Number::from(10_000_000_000_000_000_000_i128).0,
+            N::PosInt(10_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            Number::from(i128::MIN).0,
+            N::I128(i128::MIN)
+        );
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+    }
+
+    #[test]
+    fn test_deserialize_bytes_from_base64_xstr() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(b"hello");
+
+        let bytes = Value::XStr(encoded).deserialize_bytes(BytesVisitor).unwrap();
+        assert_eq!(bytes, b"hello");
+    }
+
+    #[test]
+    fn test_deserialize_bytes_from_upload_file() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("axum_params_test_{}.bin", std::process::id()));
+        std::fs::write(&path, b"file contents").unwrap();
+
+        let file = UploadFile {
+            name: "upload.bin".to_string(),
+            content_type: "application/octet-stream".to_string(),
+            temp_file_path: path.to_string_lossy().to_string(),
+        };
+
+        let bytes = Value::UploadFile(file)
+            .deserialize_bytes(BytesVisitor)
+            .unwrap();
+        assert_eq!(bytes, b"file contents");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_deserialize_bytes_percent_decodes_base64_xstr() {
+        // Raw base64 can contain `+`, `/`, `=`, which query/form encoders
+        // percent-escape; the XStr arm must requote before decoding.
+        let payload: Vec<u8> = (0u8..=255).collect();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&payload);
+        assert!(
+            encoded.contains('+') && encoded.contains('/'),
+            "fixture should exercise both special base64 characters"
+        );
+        let percent_encoded = encoded.replace('+', "%2B").replace('/', "%2F");
+
+        let bytes = Value::XStr(percent_encoded)
+            .deserialize_bytes(BytesVisitor)
+            .unwrap();
+        assert_eq!(bytes, payload);
+    }
+
+    #[test]
+    fn test_deserialize_bytes_rejects_upload_file_over_size_limit() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("axum_params_test_oversized_{}.bin", std::process::id()));
+        {
+            let file = std::fs::File::create(&path).unwrap();
+            file.set_len(MAX_INLINE_UPLOAD_BYTES + 1).unwrap();
+        }
+
+        let upload = UploadFile {
+            name: "big.bin".to_string(),
+            content_type: "application/octet-stream".to_string(),
+            temp_file_path: path.to_string_lossy().to_string(),
+        };
+
+        let result = Value::UploadFile(upload).deserialize_bytes(BytesVisitor);
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct FileUpload {
+        #[serde(with = "serde_bytes")]
+        data: Vec<u8>,
+    }
+
+    #[test]
+    fn test_deserialize_upload_file_into_serde_bytes_field() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("axum_params_test_field_{}.bin", std::process::id()));
+        std::fs::write(&path, b"field contents").unwrap();
+
+        let file = UploadFile {
+            name: "upload.bin".to_string(),
+            content_type: "application/octet-stream".to_string(),
+            temp_file_path: path.to_string_lossy().to_string(),
+        };
+        let mut fields = HashMap::new();
+        fields.insert("data".to_string(), Value::UploadFile(file));
+
+        let upload = FileUpload::deserialize(Value::Object(fields)).unwrap();
+        assert_eq!(upload.data, b"field contents");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    pub struct Scalars {
+        pub name: String,
+        pub flag: bool,
+        pub count: i32,
+    }
+
+    #[test]
+    fn test_percent_decodes_xstr_scalars_before_coercion() {
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Value::XStr("us%64".to_string()));
+        fields.insert("flag".to_string(), Value::XStr("%74rue".to_string()));
+        fields.insert("count".to_string(), Value::XStr("%31%30".to_string()));
+
+        let scalars = Scalars::deserialize(Value::Object(fields)).unwrap();
+        assert_eq!(scalars.name, "usd");
+        assert!(scalars.flag);
+        assert_eq!(scalars.count, 10);
+    }
+
+    #[test]
+    fn test_percent_decoding_leaves_json_strings_untouched() {
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Value::String("us%64".to_string()));
+        fields.insert("flag".to_string(), Value::Bool(true));
+        fields.insert("count".to_string(), Value::Number(Number::from(10i64)));
+
+        let scalars = Scalars::deserialize(Value::Object(fields)).unwrap();
+        assert_eq!(scalars.name, "us%64");
+    }
+
+    #[test]
+    fn test_deserialize_enum_rejects_multi_key_object() {
+        let mut variant = HashMap::new();
+        variant.insert("circle".to_string(), Value::XStr("2.5".to_string()));
+        variant.insert("point".to_string(), Value::XStr("1.0".to_string()));
+
+        let result = Shape::deserialize(Value::Object(variant));
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_deserialize_enum_from_query_params() {
         let setup_logger = || {
@@ -0,0 +1,13 @@
+/// Metadata for a file uploaded via multipart form data, plus the on-disk
+/// location its body was streamed to.
+///
+/// To read the body into a field instead of this metadata, the field must
+/// opt in with `#[serde(with = "serde_bytes")]` (or `serde_bytes::ByteBuf`) —
+/// a plain `Vec<u8>` derives a `deserialize_seq` call, which never reaches
+/// the byte-buffer support on the `Value` deserializer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFile {
+    pub name: String,
+    pub content_type: String,
+    pub temp_file_path: String,
+}
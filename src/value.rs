@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crate::UploadFile;
+
+/// The numeric representation backing [`Number`], picking the narrowest
+/// variant that can hold a given value without loss.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum N {
+    PosInt(u64),
+    NegInt(i64),
+    Float(f64),
+    I128(i128),
+    U128(u128),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number(pub N);
+
+impl From<u64> for Number {
+    fn from(v: u64) -> Self {
+        Number(N::PosInt(v))
+    }
+}
+
+impl From<i64> for Number {
+    fn from(v: i64) -> Self {
+        match u64::try_from(v) {
+            Ok(v) => Number(N::PosInt(v)),
+            Err(_) => Number(N::NegInt(v)),
+        }
+    }
+}
+
+impl From<f64> for Number {
+    fn from(v: f64) -> Self {
+        Number(N::Float(v))
+    }
+}
+
+impl From<u128> for Number {
+    fn from(v: u128) -> Self {
+        match u64::try_from(v) {
+            Ok(v) => Number(N::PosInt(v)),
+            Err(_) => Number(N::U128(v)),
+        }
+    }
+}
+
+impl From<i128> for Number {
+    fn from(v: i128) -> Self {
+        if let Ok(v) = i64::try_from(v) {
+            return Number::from(v);
+        }
+        match u64::try_from(v) {
+            Ok(v) => Number(N::PosInt(v)),
+            Err(_) => Number(N::I128(v)),
+        }
+    }
+}
+
+/// A value parsed out of a request: JSON body, query string, or multipart
+/// form data, all normalized to this shape before being handed to serde.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(Number),
+    /// A string that came from JSON and is already fully decoded.
+    String(String),
+    /// A string that came from a query string or form body and may still
+    /// need percent-decoding before it's parsed as a scalar.
+    XStr(String),
+    Array(Vec<Value>),
+    Object(HashMap<String, Value>),
+    /// See [`UploadFile`] for what's required to deserialize this into a
+    /// byte buffer instead of its metadata map.
+    UploadFile(UploadFile),
+}